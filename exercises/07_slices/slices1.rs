@@ -0,0 +1,22 @@
+// TODO: Fix the compiler error by borrowing a string slice (`&str`) instead
+// of taking ownership of a `String`.
+
+fn count_vowels(text: String) -> usize {
+    text.chars().filter(|c| "aeiouAEIOU".contains(*c)).count()
+}
+
+fn main() {
+    let sentence = String::from("The quick brown fox");
+    let vowels = count_vowels(sentence);
+    println!("\"{sentence}\" has {vowels} vowels");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices1() {
+        assert_eq!(count_vowels("The quick brown fox"), 5);
+    }
+}
@@ -0,0 +1,29 @@
+// TODO: Fix the compiler error by having `largest` borrow a slice (`&[i32]`)
+// instead of taking ownership of a `Vec<i32>`.
+
+fn largest(numbers: Vec<i32>) -> i32 {
+    *numbers.iter().max().unwrap()
+}
+
+fn main() {
+    let data = vec![34, 50, 25, 100, 65];
+
+    // `largest` should be able to look at a window into `data` without
+    // taking ownership of the whole vector.
+    let max_of_first_three = largest(&data[..3]);
+    let max_overall = largest(&data[..]);
+
+    println!("max of first three: {max_of_first_three}, max overall: {max_overall}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices2() {
+        let data = vec![34, 50, 25, 100, 65];
+        assert_eq!(largest(&data[..3]), 50);
+        assert_eq!(largest(&data[..]), 100);
+    }
+}
@@ -0,0 +1,28 @@
+// TODO: Fix the compiler error without changing `first_word`. `word`
+// borrows `sentence`, so `sentence` can't be mutated (e.g. with `.clear()`)
+// while that borrow is still alive — print `word` before clearing.
+
+fn first_word(sentence: &str) -> &str {
+    sentence.split_whitespace().next().unwrap_or("")
+}
+
+fn main() {
+    let mut sentence = String::from("the quick brown fox");
+
+    let word = first_word(&sentence);
+
+    sentence.clear();
+
+    println!("first word: {word}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices3() {
+        assert_eq!(first_word("the quick brown fox"), "the");
+        assert_eq!(first_word(""), "");
+    }
+}
@@ -0,0 +1,155 @@
+//! Curated explanations for a handful of `rustc` error codes, keyed off
+//! the four ways to satisfy the borrow checker: move, `&`, `&mut`, or
+//! `clone`. [`explain`] is called from [`crate::exercise::Exercise::compile`]'s
+//! caller in place of printing the raw diagnostic message.
+
+use std::fmt;
+
+/// The four standard ways to satisfy the borrow checker, as taught by the
+/// ownership exercises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorrowFix {
+    Move,
+    Borrow,
+    BorrowMut,
+    Clone,
+}
+
+impl fmt::Display for BorrowFix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            BorrowFix::Move => "take ownership (move)",
+            BorrowFix::Borrow => "borrow immutably (`&`)",
+            BorrowFix::BorrowMut => "borrow mutably (`&mut`)",
+            BorrowFix::Clone => "clone the value (`.clone()`)",
+        };
+        f.write_str(s)
+    }
+}
+
+const ALL_FIXES: [BorrowFix; 4] = [
+    BorrowFix::Move,
+    BorrowFix::Borrow,
+    BorrowFix::BorrowMut,
+    BorrowFix::Clone,
+];
+
+/// A single span attached to a `rustc` diagnostic, e.g. the "value moved
+/// here" / "value used here" locations on an `E0382`, or the "expected
+/// `i32`, found `()`" label on an `E0308`.
+#[derive(Debug, Clone)]
+pub struct DiagnosticSpan {
+    pub line_start: usize,
+    pub label: Option<String>,
+}
+
+/// The subset of a `rustc` JSON diagnostic this module needs: the error
+/// code, the headline message, and its spans in source order.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub code: String,
+    pub message: String,
+    pub spans: Vec<DiagnosticSpan>,
+}
+
+/// Render a curated explanation for `diagnostic`, or `None` if this error
+/// code isn't one we have a teaching note for (the runner should fall back
+/// to printing the raw `rustc` output in that case).
+pub fn explain(diagnostic: &Diagnostic) -> Option<String> {
+    match diagnostic.code.as_str() {
+        "E0382" => Some(explain_moved_value(diagnostic)),
+        "E0499" => Some(explain_two_mutable_borrows()),
+        "E0502" => Some(explain_immutable_and_mutable_borrow()),
+        _ => None,
+    }
+}
+
+fn explain_moved_value(diagnostic: &Diagnostic) -> String {
+    let var_name = extract_backtick_name(&diagnostic.message).unwrap_or_else(|| "value".into());
+    let (moved_at, used_at) = match diagnostic.spans.as_slice() {
+        [moved, used, ..] => (moved.line_start, used.line_start),
+        [moved] => (moved.line_start, moved.line_start),
+        [] => (0, 0),
+    };
+
+    let mut out = format!(
+        "value `{var_name}` moved at line {moved_at}, used again at line {used_at} — choose one:\n"
+    );
+    for fix in ALL_FIXES {
+        out.push_str(&format!("  - {fix}\n"));
+    }
+    out.push_str(&format!(
+        "Concretely: add `.clone()` at the move site, change the parameter to `&{var_name}`, \
+         or restructure the code so only one owner exists."
+    ));
+    out
+}
+
+fn explain_two_mutable_borrows() -> String {
+    "two `&mut` borrows of the same value are alive at once — only one mutable borrow is \
+     allowed at a time. Choose one: end the first borrow before taking the second, borrow \
+     immutably (`&`) instead if you don't need to mutate, move ownership so there's only one \
+     borrower, or `clone()` the value so each call gets its own copy."
+        .to_string()
+}
+
+fn explain_immutable_and_mutable_borrow() -> String {
+    "an immutable (`&`) and a mutable (`&mut`) borrow of the same value overlap — Rust forbids \
+     mutating while a shared reference is live. Choose one: drop the immutable borrow before \
+     mutating, take the mutable borrow once the immutable one is no longer used, move ownership \
+     so only the mutator has access, or `clone()` the data being read."
+        .to_string()
+}
+
+/// Pull the first backtick-quoted identifier out of a rustc message, e.g.
+/// ``use of moved value: `data` ``.
+fn extract_backtick_name(message: &str) -> Option<String> {
+    message.split('`').nth(1).map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explains_moved_value_with_var_name_and_lines() {
+        let diagnostic = Diagnostic {
+            code: "E0382".to_string(),
+            message: "use of moved value: `data`".to_string(),
+            spans: vec![DiagnosticSpan { line_start: 8, label: None }, DiagnosticSpan { line_start: 12, label: None }],
+        };
+        let explanation = explain(&diagnostic).unwrap();
+        assert!(explanation.contains("`data` moved at line 8, used again at line 12"));
+        assert!(explanation.contains("&data"));
+    }
+
+    #[test]
+    fn explains_two_mutable_borrows() {
+        let diagnostic = Diagnostic {
+            code: "E0499".to_string(),
+            message: "cannot borrow as mutable more than once".to_string(),
+            spans: vec![],
+        };
+        assert!(explain(&diagnostic).unwrap().contains("only one mutable borrow"));
+    }
+
+    #[test]
+    fn explains_immutable_and_mutable_borrow() {
+        let diagnostic = Diagnostic {
+            code: "E0502".to_string(),
+            message: "cannot borrow as mutable because it is also borrowed as immutable".to_string(),
+            spans: vec![],
+        };
+        assert!(explain(&diagnostic).unwrap().contains("mutating while a shared reference"));
+    }
+
+    #[test]
+    fn unrecognized_codes_fall_back_to_raw_output() {
+        let diagnostic = Diagnostic {
+            code: "E9999".to_string(),
+            message: "some other error".to_string(),
+            spans: vec![],
+        };
+        assert!(explain(&diagnostic).is_none());
+    }
+}
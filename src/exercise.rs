@@ -0,0 +1,174 @@
+//! Locates and compiles a single exercise by name, turning the `rustc`
+//! diagnostics it fails with into the structured [`Diagnostic`]s that
+//! [`crate::diagnostics::explain`] knows how to annotate.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Deserialize;
+
+use crate::diagnostics::{Diagnostic, DiagnosticSpan};
+use crate::info;
+
+pub struct Exercise {
+    pub name: String,
+    pub path: PathBuf,
+    is_test: bool,
+}
+
+/// Why an exercise didn't pass: either it failed to compile, or it
+/// compiled but its test harness (for `test = true` exercises) or its
+/// `main` (otherwise) exited unsuccessfully.
+pub enum Outcome {
+    Diagnostics(Vec<Diagnostic>),
+    RuntimeFailure { stdout: String, stderr: String },
+}
+
+impl Exercise {
+    /// Resolve the exercise named `name` via `info.toml`, or `None` if
+    /// it isn't registered there.
+    pub fn named(name: &str) -> Option<Self> {
+        let info = info::find(name)?;
+        let path = info::exercise_path(name)?;
+        Some(Self { name: name.to_string(), path, is_test: info.test })
+    }
+
+    /// Compile this exercise and, if that succeeds, run it (its test
+    /// harness if `test = true` in `info.toml`, otherwise its `main`).
+    /// Only `Ok` means the exercise actually passes.
+    pub fn compile(&self) -> Result<(), Outcome> {
+        let binary_path = rustc_compile(&self.path, self.is_test).map_err(Outcome::Diagnostics)?;
+
+        let run_output =
+            Command::new(&binary_path).output().expect("failed to run compiled exercise");
+        if run_output.status.success() {
+            Ok(())
+        } else {
+            Err(Outcome::RuntimeFailure {
+                stdout: String::from_utf8_lossy(&run_output.stdout).into_owned(),
+                stderr: String::from_utf8_lossy(&run_output.stderr).into_owned(),
+            })
+        }
+    }
+}
+
+/// Compile `path` with `rustc`, returning the compiled binary's path on
+/// success, or the structured diagnostics it reported on failure.
+///
+/// `is_test` selects `rustc --test` (so `#[cfg(test)] mod tests` isn't
+/// stripped and the output is a test harness) instead of
+/// `--crate-type=bin`.
+pub fn rustc_compile(path: &Path, is_test: bool) -> Result<PathBuf, Vec<Diagnostic>> {
+    let out_path = std::env::temp_dir()
+        .join(format!("rustlings-{}", path.file_stem().unwrap().to_string_lossy()));
+
+    let mut command = Command::new("rustc");
+    command.args(["--edition", "2021", "--error-format=json"]);
+    if is_test {
+        command.arg("--test");
+    } else {
+        command.arg("--crate-type=bin");
+    }
+    let output =
+        command.arg("-o").arg(&out_path).arg(path).output().expect("failed to invoke rustc");
+
+    if output.status.success() {
+        return Ok(out_path);
+    }
+
+    let diagnostics =
+        String::from_utf8_lossy(&output.stderr).lines().filter_map(parse_rustc_json_line).collect();
+    Err(diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_fixture(name: &str, source: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("rustlings-exercise-test-{name}.rs"));
+        std::fs::write(&path, source).unwrap();
+        path
+    }
+
+    #[test]
+    fn rustc_compile_without_test_flag_strips_cfg_test_blocks() {
+        let path = write_fixture(
+            "no-test-flag",
+            "fn main() {}\n\
+             #[cfg(test)]\n\
+             mod tests {\n    #[test]\n    fn always_fails() { assert!(false); }\n}\n",
+        );
+        let binary_path = rustc_compile(&path, false).expect("fixture should compile as a bin");
+        let output = Command::new(binary_path).output().unwrap();
+        assert!(output.status.success(), "main should run fine; the failing test was stripped");
+    }
+
+    #[test]
+    fn rustc_compile_with_test_flag_keeps_cfg_test_blocks() {
+        let path = write_fixture(
+            "with-test-flag",
+            "fn main() {}\n\
+             #[cfg(test)]\n\
+             mod tests {\n    #[test]\n    fn always_fails() { assert!(false); }\n}\n",
+        );
+        let binary_path = rustc_compile(&path, true).expect("fixture should compile as a test harness");
+        let output = Command::new(binary_path).output().unwrap();
+        assert!(!output.status.success(), "the harness should run and report the failing test");
+    }
+
+    #[test]
+    fn parses_mismatched_types_label_from_a_span() {
+        let line = r#"{"message":"mismatched types","code":{"code":"E0308"},"level":"error","spans":[{"line_start":4,"label":"expected `i32`, found `()`"}]}"#;
+        let diagnostic = parse_rustc_json_line(line).unwrap();
+        assert_eq!(diagnostic.code, "E0308");
+        assert_eq!(diagnostic.spans[0].label.as_deref(), Some("expected `i32`, found `()`"));
+    }
+
+    #[test]
+    fn non_error_level_lines_are_skipped() {
+        let line = r#"{"message":"unused variable","code":null,"level":"warning","spans":[]}"#;
+        assert!(parse_rustc_json_line(line).is_none());
+    }
+
+    #[test]
+    fn non_diagnostic_lines_are_skipped() {
+        assert!(parse_rustc_json_line("error: aborting due to 1 previous error").is_none());
+    }
+}
+
+#[derive(Deserialize)]
+struct RawDiagnostic {
+    message: String,
+    code: Option<RawCode>,
+    level: String,
+    spans: Vec<RawSpan>,
+}
+
+#[derive(Deserialize)]
+struct RawCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct RawSpan {
+    line_start: usize,
+    label: Option<String>,
+}
+
+/// Parse one line of `rustc --error-format=json` output into a
+/// [`Diagnostic`], skipping non-error-level messages and lines that
+/// aren't diagnostics at all (e.g. the final "N errors emitted" summary).
+fn parse_rustc_json_line(line: &str) -> Option<Diagnostic> {
+    let raw: RawDiagnostic = serde_json::from_str(line).ok()?;
+    if raw.level != "error" {
+        return None;
+    }
+    let code = raw.code?.code;
+    let spans = raw
+        .spans
+        .into_iter()
+        .map(|span| DiagnosticSpan { line_start: span.line_start, label: span.label })
+        .collect();
+    Some(Diagnostic { code, message: raw.message, spans })
+}
@@ -0,0 +1,38 @@
+//! Reads the exercise manifest (`info.toml`) at the repo root.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    exercises: Vec<ExerciseInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExerciseInfo {
+    pub name: String,
+    pub dir: String,
+    /// Whether this exercise is verified by compiling it with `rustc
+    /// --test` and running its embedded test harness, rather than just
+    /// running its `main`.
+    #[serde(default)]
+    pub test: bool,
+    #[serde(default)]
+    pub hint: String,
+}
+
+fn manifest() -> Manifest {
+    let contents = std::fs::read_to_string("info.toml").expect("failed to read info.toml");
+    toml::from_str(&contents).expect("failed to parse info.toml")
+}
+
+/// Find the manifest entry for `name`, if it's registered.
+pub fn find(name: &str) -> Option<ExerciseInfo> {
+    manifest().exercises.into_iter().find(|exercise| exercise.name == name)
+}
+
+/// Resolve `name`'s source file path from the manifest.
+pub fn exercise_path(name: &str) -> Option<PathBuf> {
+    find(name).map(|info| PathBuf::from("exercises").join(info.dir).join(format!("{name}.rs")))
+}
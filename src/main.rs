@@ -0,0 +1,176 @@
+mod diagnostics;
+mod exercise;
+mod info;
+mod progress;
+mod scratch;
+mod trace;
+
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+use clap::{Parser, Subcommand};
+
+use exercise::{Exercise, Outcome};
+use progress::ProgressStore;
+
+#[derive(Parser)]
+#[command(name = "rustlings")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Compile an exercise, printing a curated explanation in place of raw
+    /// `rustc` output for error codes we have one for.
+    Run { name: String },
+    /// Print the manifest hint for an exercise.
+    Hint { name: String },
+    /// Render an ASCII lifetime/scope timeline for an exercise.
+    Trace { name: String },
+    /// Compile and run a candidate body for `square` in `functions5`, e.g.
+    /// `rustlings scratch '{ num * num; }'`.
+    Scratch { body: String },
+    /// Recompile an exercise every time its source file changes, escalating
+    /// the hint shown after each repeated failure.
+    Watch { name: String },
+}
+
+fn progress_path() -> PathBuf {
+    PathBuf::from(".rustlings-progress.json")
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Commands::Run { name } => {
+            run(&name);
+        }
+        Commands::Hint { name } => hint(&name),
+        Commands::Trace { name } => trace_cmd(&name),
+        Commands::Scratch { body } => scratch_cmd(&body),
+        Commands::Watch { name } => watch_cmd(&name),
+    }
+}
+
+/// Compile and run `name` once, printing curated diagnostics and an
+/// attempt-escalated hint on failure. Returns whether it actually passed
+/// (compiled *and* ran/tested successfully), so [`watch_cmd`] can report
+/// pass/fail without duplicating this logic.
+fn run(name: &str) -> bool {
+    let Some(exercise) = Exercise::named(name) else {
+        eprintln!("no exercise named `{name}`");
+        return false;
+    };
+    let mut store = ProgressStore::load(progress_path());
+
+    match exercise.compile() {
+        Ok(()) => {
+            println!("✓ {} passes", exercise.name);
+            store.record_pass(name);
+            let _ = store.save();
+            true
+        }
+        Err(outcome) => {
+            let attempts = store.record_failed_attempt(name);
+            let _ = store.save();
+
+            match outcome {
+                Outcome::Diagnostics(diagnostics) => {
+                    for diagnostic in &diagnostics {
+                        match diagnostics::explain(diagnostic) {
+                            Some(explanation) => println!("{explanation}"),
+                            None => println!("{}", diagnostic.message),
+                        }
+                    }
+                }
+                Outcome::RuntimeFailure { stdout, stderr } => {
+                    print!("{stdout}");
+                    eprint!("{stderr}");
+                }
+            }
+            println!();
+            println!("{}", progress::escalate(name, attempts));
+            false
+        }
+    }
+}
+
+fn hint(name: &str) {
+    match info::find(name) {
+        Some(info) => {
+            let store = ProgressStore::load(progress_path());
+            let attempts = store.attempts_for(name);
+            if attempts > 0 {
+                println!("{}", progress::escalate(name, attempts));
+            } else {
+                println!("{}", info.hint);
+            }
+        }
+        None => eprintln!("no exercise named `{name}`"),
+    }
+}
+
+fn trace_cmd(name: &str) {
+    let Some(exercise) = Exercise::named(name) else {
+        eprintln!("no exercise named `{name}`");
+        return;
+    };
+    let source = std::fs::read_to_string(&exercise.path).expect("failed to read exercise source");
+    match trace::trace(&source) {
+        Some(timeline) => println!("{timeline}"),
+        None => eprintln!("couldn't parse `{name}` to trace its bindings"),
+    }
+}
+
+fn scratch_cmd(body: &str) {
+    let source = format!(
+        "{}\nfn main() {{ println!(\"square(3) = {{}}\", square(3)); }}\n",
+        scratch::render_candidate(body)
+    );
+    let scratch_path = std::env::temp_dir().join("rustlings-scratch.rs");
+    std::fs::write(&scratch_path, source).expect("failed to write scratch file");
+
+    match exercise::rustc_compile(&scratch_path, false) {
+        Ok(binary_path) => {
+            let output = Command::new(binary_path).output().expect("failed to run scratch binary");
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+        }
+        Err(diagnostics) => {
+            for diagnostic in &diagnostics {
+                match scratch::explain_mismatch(diagnostic, body) {
+                    Some(hint) => println!("{hint}"),
+                    None => println!("{}", diagnostic.message),
+                }
+            }
+        }
+    }
+}
+
+/// Recompile `name` every time its source file's modification time
+/// changes, so repeated failures escalate the hint via [`run`]'s progress
+/// tracking without the learner having to rerun `rustlings run` by hand.
+fn watch_cmd(name: &str) {
+    let Some(exercise) = Exercise::named(name) else {
+        eprintln!("no exercise named `{name}`");
+        return;
+    };
+    let mut last_modified = modified_time(&exercise.path);
+    println!("Watching {name} for changes (Ctrl+C to stop)...");
+    run(name);
+
+    loop {
+        std::thread::sleep(Duration::from_millis(500));
+        let modified = modified_time(&exercise.path);
+        if modified != last_modified {
+            last_modified = modified;
+            run(name);
+        }
+    }
+}
+
+fn modified_time(path: &std::path::Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
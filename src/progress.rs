@@ -0,0 +1,165 @@
+//! Persistent, per-exercise failed-attempt tracking that escalates hints.
+//!
+//! Today a learner either has no hint or the single static hint from
+//! `info.toml` — there's no middle ground. This module keeps a small JSON
+//! state file keyed by exercise name, counts how many times an exercise has
+//! failed to compile since it was last passed, and maps that count onto a
+//! tier of increasingly specific hints. Watch mode calls
+//! [`ProgressStore::record_failed_attempt`] on every failed recompile and
+//! renders [`escalate`]'s output instead of the flat `info.toml` hint.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// How many times an exercise has failed to compile without passing.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProgressStore {
+    attempts: HashMap<String, u32>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl ProgressStore {
+    /// Load the store from `path`, or start a fresh one if it doesn't
+    /// exist yet or can't be parsed.
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let mut store: Self = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        store.path = path;
+        store
+    }
+
+    /// Persist the current attempt counts back to disk.
+    pub fn save(&self) -> std::io::Result<()> {
+        let contents = serde_json::to_string_pretty(self).unwrap_or_default();
+        fs::write(&self.path, contents)
+    }
+
+    /// Record another failed compile of `exercise` and return the new
+    /// attempt count.
+    pub fn record_failed_attempt(&mut self, exercise: &str) -> u32 {
+        let count = self.attempts.entry(exercise.to_string()).or_insert(0);
+        *count += 1;
+        *count
+    }
+
+    /// Reset an exercise's attempt count once it passes.
+    pub fn record_pass(&mut self, exercise: &str) {
+        self.attempts.remove(exercise);
+    }
+
+    pub fn attempts_for(&self, exercise: &str) -> u32 {
+        self.attempts.get(exercise).copied().unwrap_or(0)
+    }
+}
+
+/// The three escalating levels of help a learner can unlock by failing an
+/// exercise repeatedly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintTier {
+    Conceptual,
+    Mechanism,
+    NearSolution,
+}
+
+/// Map a failed-attempt count onto a hint tier: a conceptual nudge first,
+/// then the mechanism-level hint, then a near-solution pointer.
+pub fn tier_for(attempts: u32) -> HintTier {
+    match attempts {
+        0..=2 => HintTier::Conceptual,
+        3..=4 => HintTier::Mechanism,
+        _ => HintTier::NearSolution,
+    }
+}
+
+/// Render the escalated hint for `exercise` at `attempts` failed
+/// compiles. Exercises without a curated escalation fall back to a
+/// generic, still-tiered message so every exercise benefits from
+/// escalation, not just the ones with bespoke copy.
+pub fn escalate(exercise: &str, attempts: u32) -> String {
+    let tier = tier_for(attempts);
+    if exercise == "move_semantics3" {
+        return move_semantics3_hint(tier);
+    }
+    generic_hint(tier)
+}
+
+fn move_semantics3_hint(tier: HintTier) -> String {
+    match tier {
+        HintTier::Conceptual => "This is about who owns the vector.".to_string(),
+        HintTier::Mechanism => {
+            "You need exactly one of the four: move, `&`, `&mut`, or clone.".to_string()
+        }
+        HintTier::NearSolution => {
+            "`let vec0 = &mut vec![...]` makes `vec0` a `&mut Vec<i32>`, so `fill_vec` must \
+             take a `&mut Vec<i32>` too — it can't take ownership of a borrowed vector."
+                .to_string()
+        }
+    }
+}
+
+fn generic_hint(tier: HintTier) -> String {
+    match tier {
+        HintTier::Conceptual => {
+            "Re-read the comments above the function — they name the concept this exercise is \
+             testing."
+                .to_string()
+        }
+        HintTier::Mechanism => {
+            "Check `info.toml` for this exercise's hint — it names the specific mechanism to \
+             apply."
+                .to_string()
+        }
+        HintTier::NearSolution => {
+            "Compare your code line-by-line against the TODO comment; the fix is usually a \
+             single line."
+                .to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let mut path = env::temp_dir();
+        path.push(format!("rustlings-progress-test-{name}.json"));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn attempt_counts_persist_across_loads() {
+        let path = temp_path("persist");
+        let mut store = ProgressStore::load(&path);
+        store.record_failed_attempt("move_semantics3");
+        store.record_failed_attempt("move_semantics3");
+        store.save().unwrap();
+
+        let reloaded = ProgressStore::load(&path);
+        assert_eq!(reloaded.attempts_for("move_semantics3"), 2);
+    }
+
+    #[test]
+    fn passing_resets_the_count() {
+        let mut store = ProgressStore::load(temp_path("reset"));
+        store.record_failed_attempt("square");
+        store.record_pass("square");
+        assert_eq!(store.attempts_for("square"), 0);
+    }
+
+    #[test]
+    fn escalates_through_tiers_for_move_semantics3() {
+        assert_eq!(escalate("move_semantics3", 1), move_semantics3_hint(HintTier::Conceptual));
+        assert_eq!(escalate("move_semantics3", 3), move_semantics3_hint(HintTier::Mechanism));
+        assert_eq!(escalate("move_semantics3", 5), move_semantics3_hint(HintTier::NearSolution));
+    }
+}
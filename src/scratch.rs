@@ -0,0 +1,100 @@
+//! Opt-in scratch mode for the statement-vs-expression lesson in the
+//! `functions5` exercise's `square` function, wired up as the
+//! `rustlings scratch <body>` subcommand (see [`crate::scratch_cmd`] in
+//! `main.rs`). Lets a learner paste a candidate body — e.g. `{ num * num }`
+//! against `{ num * num; }` — and see immediately that the trailing
+//! semicolon turns the block into a unit-returning statement, instead of
+//! just forwarding the raw `rustc` message.
+
+use crate::diagnostics::Diagnostic;
+
+fn mentions_unit_mismatch(diagnostic: &Diagnostic) -> bool {
+    diagnostic.message.contains("found `()`")
+        || diagnostic
+            .spans
+            .iter()
+            .any(|span| span.label.as_deref().is_some_and(|label| label.contains("found `()`")))
+}
+
+/// Wraps a candidate function body in the same signature as `square` in
+/// `functions5`, ready to hand to `rustc`.
+pub fn render_candidate(body: &str) -> String {
+    format!("fn square(num: i32) -> i32 {body}\n")
+}
+
+/// If `diagnostic` is the "expected i32, found ()" mismatch this exercise
+/// is built to teach, return a hint naming the trailing-semicolon rule.
+/// Returns `None` for any other diagnostic, so the runner can fall back to
+/// printing it unmodified.
+pub fn explain_mismatch(diagnostic: &Diagnostic, body: &str) -> Option<String> {
+    if diagnostic.code != "E0308" || !mentions_unit_mismatch(diagnostic) {
+        return None;
+    }
+
+    let mut hint = "a block's trailing semicolon turns its last line into a statement, which \
+                     returns `()` instead of the value — that's why the block evaluates to \
+                     `()` instead of `i32`."
+        .to_string();
+    if let Some(offending) = last_semicolon_terminated_line(body) {
+        hint.push_str(&format!(" Remove the `;` after `{offending}` to make it the block's trailing expression."));
+    }
+    Some(hint)
+}
+
+/// If `body`'s block ends with a `;`-terminated statement, return that
+/// statement's text (trimmed, semicolon stripped) so it can be quoted back
+/// to the learner. `body` may be a single line (`{ num * num; }`) or span
+/// several, so this strips the outer braces before splitting on `;`
+/// instead of relying on line breaks.
+fn last_semicolon_terminated_line(body: &str) -> Option<String> {
+    let inner = body.trim().strip_prefix('{')?.strip_suffix('}')?.trim();
+    if !inner.ends_with(';') {
+        return None;
+    }
+    inner.split(';').map(str::trim).rfind(|stmt| !stmt.is_empty()).map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::DiagnosticSpan;
+
+    fn mismatch(message: &str) -> Diagnostic {
+        Diagnostic { code: "E0308".to_string(), message: message.to_string(), spans: vec![] }
+    }
+
+    #[test]
+    fn names_trailing_semicolon_rule_for_unit_mismatch() {
+        let diagnostic = mismatch("mismatched types: expected `i32`, found `()`");
+        let hint = explain_mismatch(&diagnostic, "{ num * num; }").unwrap();
+        assert!(hint.contains("trailing semicolon"));
+        assert!(hint.contains("num * num"));
+    }
+
+    #[test]
+    fn names_trailing_semicolon_rule_when_unit_is_only_in_the_span_label() {
+        // This is the shape `rustc --error-format=json` actually emits: the
+        // headline message is just "mismatched types", and "found `()`"
+        // only appears in a span label.
+        let diagnostic = Diagnostic {
+            code: "E0308".to_string(),
+            message: "mismatched types".to_string(),
+            spans: vec![DiagnosticSpan { line_start: 1, label: Some("expected `i32`, found `()`".to_string()) }],
+        };
+        let hint = explain_mismatch(&diagnostic, "{ num * num; }").unwrap();
+        assert!(hint.contains("trailing semicolon"));
+    }
+
+    #[test]
+    fn ignores_unrelated_type_mismatches() {
+        let diagnostic = mismatch("mismatched types: expected `i32`, found `&str`");
+        assert!(explain_mismatch(&diagnostic, "{ \"nope\" }").is_none());
+    }
+
+    #[test]
+    fn render_candidate_wraps_body_in_square_signature() {
+        let rendered = render_candidate("{ num * num }");
+        assert!(rendered.starts_with("fn square(num: i32) -> i32"));
+        assert!(rendered.contains("num * num"));
+    }
+}